@@ -0,0 +1,102 @@
+use std::fmt;
+
+use super::{decode_number, encode_number, SHORT_MAX};
+
+/// A one-byte header flag prefixed to a framed buffer, marking it as
+/// compressed by a [Compressor]. Sits in front of the length prefix that
+/// [EoWriter](super::EoWriter) writes, so it never interferes with the
+/// `[0xff, 0xff]` sentinel that [valid_for_encryption](crate::encrypt) checks
+/// on the outermost framed bytes.
+pub const COMPRESSED_FLAG: u8 = 0x01;
+
+/// A one-byte header flag marking a framed buffer as uncompressed
+pub const RAW_FLAG: u8 = 0x00;
+
+/// A pluggable compression stage for [EoWriter](super::EoWriter)/
+/// [EoReader](super::EoReader) payloads
+pub trait Compressor {
+    /// Compresses `data`, returning the compressed bytes
+    fn compress(&self, data: &[u8]) -> Vec<u8>;
+
+    /// Decompresses `data`, returning the original bytes
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, CompressionError>;
+}
+
+/// A run-length-encoding [Compressor]
+///
+/// Runs of identical bytes are written as a `(byte, run)` pair, with `run`
+/// encoded as an EO short so runs up to `SHORT_MAX - 1` bytes collapse to
+/// three bytes. This suits the highly repetitive data found in map files and
+/// `pub` tables.
+///
+/// # Examples
+/// ```
+/// use eolib::data::compression::{Compressor, RleCompressor};
+///
+/// let data = b"aaaabbbccd";
+/// let compressed = RleCompressor.compress(data);
+/// let decompressed = RleCompressor.decompress(&compressed).unwrap();
+///
+/// assert_eq!(decompressed, data);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RleCompressor;
+
+impl Compressor for RleCompressor {
+    fn compress(&self, data: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut iter = data.iter().peekable();
+
+        while let Some(&byte) = iter.next() {
+            let mut run = 1;
+
+            // `run` is stored as the first two bytes of an EO-encoded short,
+            // which can only represent up to `SHORT_MAX - 1`; letting `run`
+            // reach `SHORT_MAX` would truncate to a value that decodes as 0.
+            while run < SHORT_MAX - 1 && iter.peek() == Some(&&byte) {
+                iter.next();
+                run += 1;
+            }
+
+            let run_bytes = encode_number(run).unwrap_or([254, 254, 254, 254]);
+            out.push(byte);
+            out.extend_from_slice(&run_bytes[0..2]);
+        }
+
+        out
+    }
+
+    fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, CompressionError> {
+        let mut out = Vec::new();
+        let mut chunks = data.chunks_exact(3);
+
+        for chunk in &mut chunks {
+            let byte = chunk[0];
+            let run = decode_number(&chunk[1..3]) as usize;
+            out.extend(std::iter::repeat_n(byte, run));
+        }
+
+        if !chunks.remainder().is_empty() {
+            return Err(CompressionError::Truncated);
+        }
+
+        Ok(out)
+    }
+}
+
+/// Errors produced by a [Compressor]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionError {
+    /// The compressed stream ended in the middle of a run
+    Truncated,
+}
+
+impl fmt::Display for CompressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "compressed stream ended mid-run"),
+        }
+    }
+}
+
+impl std::error::Error for CompressionError {}
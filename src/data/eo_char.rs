@@ -0,0 +1,84 @@
+use super::{decode_number, encode_number, CHAR_MAX};
+use crate::data::EoWriterError;
+
+/// A 1-byte EO integer in the range `0..CHAR_MAX`
+///
+/// `CHAR_MAX` itself is excluded: [encode_number](super::encode_number)
+/// spills it into a second byte, which a single-byte [encode](Self::encode)
+/// can't represent.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EoChar(i32);
+
+impl EoChar {
+    /// Returns the underlying value
+    pub fn value(self) -> i32 {
+        self.0
+    }
+
+    /// Encodes this value as a single EO-encoded byte
+    ///
+    /// # Examples
+    /// ```
+    /// use eolib::data::EoChar;
+    ///
+    /// let value = EoChar::try_from(42).unwrap();
+    /// assert_eq!(value.encode().unwrap(), 43);
+    /// ```
+    pub fn encode(self) -> Result<u8, EoWriterError> {
+        let bytes = encode_number(self.0)?;
+        Ok(bytes[0])
+    }
+
+    /// Decodes an [EoChar] from `bytes`
+    ///
+    /// The decoded value is clamped to `0..CHAR_MAX` so the result always
+    /// round-trips through [encode](Self::encode), even if `bytes` encodes a
+    /// wider value than this type's single-byte width.
+    pub fn decode(bytes: &[u8]) -> Self {
+        Self(decode_number(bytes).clamp(0, CHAR_MAX - 1))
+    }
+
+    /// Adds two values, returning [None] if the result would reach [CHAR_MAX]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::try_from(self.0 + rhs.0).ok()
+    }
+
+    /// Adds two values, clamping the result to `CHAR_MAX - 1`
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self((self.0 + rhs.0).clamp(0, CHAR_MAX - 1))
+    }
+
+    /// Subtracts two values, returning [None] if the result would be negative
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::try_from(self.0 - rhs.0).ok()
+    }
+
+    /// Subtracts two values, clamping the result to `0`
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self((self.0 - rhs.0).clamp(0, CHAR_MAX - 1))
+    }
+}
+
+impl TryFrom<i32> for EoChar {
+    type Error = EoWriterError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        if !(0..CHAR_MAX).contains(&value) {
+            return Err(EoWriterError::InvalidIntValue(value as i64));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl From<EoChar> for i32 {
+    fn from(value: EoChar) -> Self {
+        value.0
+    }
+}
+
+impl From<EoChar> for i64 {
+    fn from(value: EoChar) -> Self {
+        value.0 as i64
+    }
+}
@@ -0,0 +1,92 @@
+use super::{decode_number_64, encode_number_64, EoChar, EoShort, EoThree, INT_MAX};
+use crate::data::EoWriterError;
+
+/// A 4-byte EO integer in the range `0..INT_MAX`
+///
+/// `INT_MAX` itself is excluded: [encode_number_64](super::encode_number_64)
+/// spills it into a fifth byte, which a four-byte [encode](Self::encode)
+/// can't represent.
+///
+/// Stored as an `i64` internally since [INT_MAX] is nearly twice
+/// `i32::MAX`, which is what causes [encode_number](super::encode_number)
+/// to need its negative-value workaround for this tier.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EoInt(i64);
+
+impl EoInt {
+    /// Returns the underlying value
+    pub fn value(self) -> i64 {
+        self.0
+    }
+
+    /// Encodes this value as four EO-encoded bytes
+    pub fn encode(self) -> Result<[u8; 4], EoWriterError> {
+        let bytes = encode_number_64(self.0)?;
+        Ok([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+
+    /// Decodes an [EoInt] from `bytes`
+    ///
+    /// The decoded value is clamped to `0..INT_MAX` so the result always
+    /// round-trips through [encode](Self::encode), even if `bytes` encodes a
+    /// wider value than this type's four-byte width.
+    pub fn decode(bytes: &[u8]) -> Self {
+        Self(decode_number_64(bytes).clamp(0, INT_MAX - 1))
+    }
+
+    /// Adds two values, returning [None] if the result would reach [INT_MAX]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::try_from(self.0 + rhs.0).ok()
+    }
+
+    /// Adds two values, clamping the result to `INT_MAX - 1`
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self((self.0 + rhs.0).clamp(0, INT_MAX - 1))
+    }
+
+    /// Subtracts two values, returning [None] if the result would be negative
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::try_from(self.0 - rhs.0).ok()
+    }
+
+    /// Subtracts two values, clamping the result to `0`
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self((self.0 - rhs.0).clamp(0, INT_MAX - 1))
+    }
+}
+
+impl TryFrom<i64> for EoInt {
+    type Error = EoWriterError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        if !(0..INT_MAX).contains(&value) {
+            return Err(EoWriterError::InvalidIntValue(value));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl From<EoChar> for EoInt {
+    fn from(value: EoChar) -> Self {
+        Self(value.value() as i64)
+    }
+}
+
+impl From<EoShort> for EoInt {
+    fn from(value: EoShort) -> Self {
+        Self(value.value() as i64)
+    }
+}
+
+impl From<EoThree> for EoInt {
+    fn from(value: EoThree) -> Self {
+        Self(value.value() as i64)
+    }
+}
+
+impl From<EoInt> for i64 {
+    fn from(value: EoInt) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+
+use super::compression::{CompressionError, Compressor, COMPRESSED_FLAG};
+use super::{decode_number, EoChar, EoInt, EoShort, EoThree};
+
+/// Reads bytes from an incoming EO packet
+#[derive(Debug, Clone)]
+pub struct EoReader {
+    buf: Vec<u8>,
+    position: usize,
+}
+
+impl EoReader {
+    /// Creates an [EoReader] over `buf`
+    pub fn new(buf: Vec<u8>) -> Self {
+        Self { buf, position: 0 }
+    }
+
+    /// Reads a frame written by [EoWriter::to_framed_bytes](super::EoWriter::to_framed_bytes),
+    /// transparently inflating the payload if it was compressed.
+    ///
+    /// # Examples
+    /// ```
+    /// use eolib::data::compression::RleCompressor;
+    /// use eolib::data::{EoReader, EoWriter};
+    ///
+    /// let mut writer = EoWriter::new();
+    /// writer.add_bytes(b"aaaabbbccd");
+    /// let framed = writer.to_framed_bytes(&RleCompressor).unwrap();
+    ///
+    /// let mut reader = EoReader::from_framed_bytes(&framed, &RleCompressor).unwrap();
+    /// assert_eq!(reader.get_bytes(10), Some(b"aaaabbbccd".to_vec()));
+    /// ```
+    pub fn from_framed_bytes(
+        framed: &[u8],
+        compressor: &impl Compressor,
+    ) -> Result<Self, EoReaderError> {
+        if framed.len() < 3 {
+            return Err(EoReaderError::Truncated);
+        }
+
+        let flag = framed[0];
+        let len = decode_number(&framed[1..3]) as usize;
+        let payload = &framed[3..];
+
+        if payload.len() < len {
+            return Err(EoReaderError::Truncated);
+        }
+        let payload = &payload[..len];
+
+        let buf = if flag == COMPRESSED_FLAG {
+            compressor
+                .decompress(payload)
+                .map_err(EoReaderError::Decompression)?
+        } else {
+            payload.to_vec()
+        };
+
+        Ok(Self::new(buf))
+    }
+
+    /// Returns the number of bytes not yet read
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.position
+    }
+
+    /// Returns the current read position
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Reads a single raw byte, advancing the position by one
+    pub fn get_byte(&mut self) -> Option<u8> {
+        let byte = *self.buf.get(self.position)?;
+        self.position += 1;
+        Some(byte)
+    }
+
+    /// Reads `length` raw bytes, advancing the position by `length`
+    pub fn get_bytes(&mut self, length: usize) -> Option<Vec<u8>> {
+        if self.remaining() < length {
+            return None;
+        }
+
+        let bytes = self.buf[self.position..self.position + length].to_vec();
+        self.position += length;
+        Some(bytes)
+    }
+
+    /// Reads an [EoChar], advancing the position by one
+    pub fn get_char(&mut self) -> Option<EoChar> {
+        self.get_bytes(1).map(|bytes| EoChar::decode(&bytes))
+    }
+
+    /// Reads an [EoShort], advancing the position by two
+    pub fn get_short(&mut self) -> Option<EoShort> {
+        self.get_bytes(2).map(|bytes| EoShort::decode(&bytes))
+    }
+
+    /// Reads an [EoThree], advancing the position by three
+    pub fn get_three(&mut self) -> Option<EoThree> {
+        self.get_bytes(3).map(|bytes| EoThree::decode(&bytes))
+    }
+
+    /// Reads an [EoInt], advancing the position by four
+    pub fn get_int(&mut self) -> Option<EoInt> {
+        self.get_bytes(4).map(|bytes| EoInt::decode(&bytes))
+    }
+}
+
+/// Errors produced while reading EO data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EoReaderError {
+    /// The buffer ended before the expected data could be read
+    Truncated,
+    /// The compressed payload could not be decompressed
+    Decompression(CompressionError),
+}
+
+impl fmt::Display for EoReaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "buffer ended before the expected data could be read"),
+            Self::Decompression(err) => write!(f, "failed to decompress payload: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for EoReaderError {}
@@ -0,0 +1,88 @@
+use super::{decode_number, encode_number, EoChar, EoShort, THREE_MAX};
+use crate::data::EoWriterError;
+
+/// A 3-byte EO integer in the range `0..THREE_MAX`
+///
+/// `THREE_MAX` itself is excluded: [encode_number](super::encode_number)
+/// spills it into a fourth byte, which a three-byte [encode](Self::encode)
+/// can't represent.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EoThree(i32);
+
+impl EoThree {
+    /// Returns the underlying value
+    pub fn value(self) -> i32 {
+        self.0
+    }
+
+    /// Encodes this value as three EO-encoded bytes
+    pub fn encode(self) -> Result<[u8; 3], EoWriterError> {
+        let bytes = encode_number(self.0)?;
+        Ok([bytes[0], bytes[1], bytes[2]])
+    }
+
+    /// Decodes an [EoThree] from `bytes`
+    ///
+    /// The decoded value is clamped to `0..THREE_MAX` so the result always
+    /// round-trips through [encode](Self::encode), even if `bytes` encodes a
+    /// wider value than this type's three-byte width.
+    pub fn decode(bytes: &[u8]) -> Self {
+        Self(decode_number(bytes).clamp(0, THREE_MAX - 1))
+    }
+
+    /// Adds two values, returning [None] if the result would reach [THREE_MAX]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::try_from(self.0 + rhs.0).ok()
+    }
+
+    /// Adds two values, clamping the result to `THREE_MAX - 1`
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self((self.0 + rhs.0).clamp(0, THREE_MAX - 1))
+    }
+
+    /// Subtracts two values, returning [None] if the result would be negative
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::try_from(self.0 - rhs.0).ok()
+    }
+
+    /// Subtracts two values, clamping the result to `0`
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self((self.0 - rhs.0).clamp(0, THREE_MAX - 1))
+    }
+}
+
+impl TryFrom<i32> for EoThree {
+    type Error = EoWriterError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        if !(0..THREE_MAX).contains(&value) {
+            return Err(EoWriterError::InvalidIntValue(value as i64));
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl From<EoChar> for EoThree {
+    fn from(value: EoChar) -> Self {
+        Self(value.value())
+    }
+}
+
+impl From<EoShort> for EoThree {
+    fn from(value: EoShort) -> Self {
+        Self(value.value())
+    }
+}
+
+impl From<EoThree> for i32 {
+    fn from(value: EoThree) -> Self {
+        value.0
+    }
+}
+
+impl From<EoThree> for i64 {
+    fn from(value: EoThree) -> Self {
+        value.0 as i64
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+
+use super::compression::{Compressor, COMPRESSED_FLAG, RAW_FLAG};
+use super::{encode_number, EoChar, EoInt, EoShort, EoThree, SHORT_MAX};
+
+/// Accumulates bytes for an outgoing EO packet
+///
+/// # Examples
+/// ```
+/// use eolib::data::{EoChar, EoWriter};
+///
+/// let mut writer = EoWriter::new();
+/// writer.add_byte(0x01);
+/// writer.add_char(EoChar::try_from(42).unwrap()).unwrap();
+///
+/// assert_eq!(writer.to_byte_array(), [0x01, 43]);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct EoWriter {
+    buf: Vec<u8>,
+}
+
+impl EoWriter {
+    /// Creates an empty [EoWriter]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a raw byte to the buffer
+    pub fn add_byte(&mut self, byte: u8) {
+        self.buf.push(byte);
+    }
+
+    /// Adds raw bytes to the buffer
+    pub fn add_bytes(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Adds an [EoChar] to the buffer
+    pub fn add_char(&mut self, value: EoChar) -> Result<(), EoWriterError> {
+        self.buf.push(value.encode()?);
+        Ok(())
+    }
+
+    /// Adds an [EoShort] to the buffer
+    pub fn add_short(&mut self, value: EoShort) -> Result<(), EoWriterError> {
+        self.buf.extend_from_slice(&value.encode()?);
+        Ok(())
+    }
+
+    /// Adds an [EoThree] to the buffer
+    pub fn add_three(&mut self, value: EoThree) -> Result<(), EoWriterError> {
+        self.buf.extend_from_slice(&value.encode()?);
+        Ok(())
+    }
+
+    /// Adds an [EoInt] to the buffer
+    pub fn add_int(&mut self, value: EoInt) -> Result<(), EoWriterError> {
+        self.buf.extend_from_slice(&value.encode()?);
+        Ok(())
+    }
+
+    /// Returns the number of bytes written so far
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns `true` if no bytes have been written
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Returns the accumulated bytes, unframed
+    pub fn to_byte_array(&self) -> Vec<u8> {
+        self.buf.clone()
+    }
+
+    /// Frames the accumulated buffer for sending: a one-byte flag marking
+    /// whether `compressor` was able to shrink the payload, an EO short
+    /// length prefix, then the (possibly compressed) payload.
+    ///
+    /// The flag is always [COMPRESSED_FLAG](super::compression::COMPRESSED_FLAG)
+    /// or [RAW_FLAG](super::compression::RAW_FLAG) — never `0xff` — so it can
+    /// never be mistaken for the `[0xff, 0xff]` sentinel that
+    /// [valid_for_encryption](crate::encrypt) checks on the outermost framed
+    /// bytes. Compression is skipped if it wouldn't shrink the payload.
+    ///
+    /// The length prefix is an EO short, so the (possibly compressed) payload
+    /// can't be `SHORT_MAX` bytes or larger — this returns
+    /// [EoWriterError::PayloadTooLarge] rather than emitting a frame whose
+    /// length silently truncates. Split large bodies (e.g. map or `pub` file
+    /// transfers) into multiple frames with [fragment](super::fragment::fragment)
+    /// first.
+    ///
+    /// # Examples
+    /// ```
+    /// use eolib::data::compression::RleCompressor;
+    /// use eolib::data::EoWriter;
+    ///
+    /// let mut writer = EoWriter::new();
+    /// writer.add_bytes(&[b'a'; 32]);
+    ///
+    /// let framed = writer.to_framed_bytes(&RleCompressor).unwrap();
+    /// assert_eq!(framed[0], eolib::data::compression::COMPRESSED_FLAG);
+    /// ```
+    pub fn to_framed_bytes(
+        &self,
+        compressor: &impl Compressor,
+    ) -> Result<Vec<u8>, EoWriterError> {
+        let compressed = compressor.compress(&self.buf);
+
+        let (flag, payload) = if compressed.len() < self.buf.len() {
+            (COMPRESSED_FLAG, compressed)
+        } else {
+            (RAW_FLAG, self.buf.clone())
+        };
+
+        if payload.len() >= SHORT_MAX as usize {
+            return Err(EoWriterError::PayloadTooLarge { len: payload.len() });
+        }
+
+        let len_bytes = encode_number(payload.len() as i32)?;
+
+        let mut out = Vec::with_capacity(payload.len() + 3);
+        out.push(flag);
+        out.extend_from_slice(&len_bytes[0..2]);
+        out.extend_from_slice(&payload);
+
+        Ok(out)
+    }
+}
+
+/// Errors produced while writing EO data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EoWriterError {
+    /// The value is out of range for the number of bytes being encoded
+    InvalidIntValue(i64),
+    /// The framed payload is too large to fit a 2-byte EO length prefix
+    PayloadTooLarge {
+        /// The payload's length in bytes
+        len: usize,
+    },
+}
+
+impl fmt::Display for EoWriterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidIntValue(value) => write!(f, "invalid int value: {value}"),
+            Self::PayloadTooLarge { len } => {
+                write!(f, "framed payload of {len} bytes exceeds the 2-byte EO length prefix")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EoWriterError {}
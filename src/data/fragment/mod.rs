@@ -0,0 +1,11 @@
+//! Packet fragmentation and reassembly for payloads that exceed [SHORT_MAX]
+//! bytes and therefore can't be framed by a single EO length prefix, e.g.
+//! map and `pub` file transfers.
+//!
+//! [SHORT_MAX]: super::SHORT_MAX
+
+mod split;
+pub use split::{fragment, Fragment, FragmentError};
+
+mod reassembler;
+pub use reassembler::{Reassembler, ReassemblerError};
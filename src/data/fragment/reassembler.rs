@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use super::{Fragment, FragmentError};
+
+/// Accumulates [Fragment]s produced by [fragment](super::fragment) back into
+/// the original buffer.
+///
+/// Fragments must be fed in sequence order; anything else is rejected so a
+/// caller never silently reassembles a corrupt buffer.
+///
+/// # Examples
+/// ```
+/// use eolib::data::fragment::{fragment, Reassembler};
+///
+/// let fragments = fragment(b"hello world", 4);
+/// let mut reassembler = Reassembler::new(4);
+///
+/// let mut result = None;
+/// for fragment in fragments {
+///     result = reassembler.accept(fragment).unwrap();
+/// }
+///
+/// assert_eq!(result.unwrap(), b"hello world");
+/// ```
+#[derive(Debug, Default)]
+pub struct Reassembler {
+    max_payload: usize,
+    next_sequence: u16,
+    buf: Vec<u8>,
+}
+
+impl Reassembler {
+    /// Creates a new [Reassembler] that rejects fragments whose payload
+    /// exceeds `max_payload` bytes.
+    pub fn new(max_payload: usize) -> Self {
+        Self {
+            max_payload,
+            next_sequence: 0,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Feeds a single fragment into the reassembler.
+    ///
+    /// Returns `Some(buf)` with the reassembled buffer once the final
+    /// fragment (`more == false`) has been accepted, or `None` if more
+    /// fragments are still expected. The sequence counter resets afterwards,
+    /// so the same [Reassembler] can be fed a second fragmented message.
+    pub fn accept(&mut self, fragment: Fragment) -> Result<Option<Vec<u8>>, ReassemblerError> {
+        if fragment.payload.len() > self.max_payload {
+            return Err(ReassemblerError::Fragment(FragmentError::PayloadTooLarge {
+                sequence: fragment.sequence,
+                len: fragment.payload.len(),
+                max_payload: self.max_payload,
+            }));
+        }
+
+        match fragment.sequence.cmp(&self.next_sequence) {
+            Ordering::Less => return Err(ReassemblerError::DuplicateSequence(fragment.sequence)),
+            Ordering::Greater => {
+                return Err(ReassemblerError::OutOfRangeSequence {
+                    expected: self.next_sequence,
+                    got: fragment.sequence,
+                })
+            }
+            Ordering::Equal => {}
+        }
+
+        self.buf.extend_from_slice(&fragment.payload);
+        self.next_sequence = self.next_sequence.wrapping_add(1);
+
+        if fragment.more {
+            Ok(None)
+        } else {
+            self.next_sequence = 0;
+            Ok(Some(std::mem::take(&mut self.buf)))
+        }
+    }
+}
+
+/// Errors produced while reassembling a fragmented buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReassemblerError {
+    /// A fragment with an already-consumed sequence index was received
+    DuplicateSequence(u16),
+    /// A fragment arrived out of order
+    OutOfRangeSequence { expected: u16, got: u16 },
+    /// The fragment itself failed validation
+    Fragment(FragmentError),
+}
+
+impl fmt::Display for ReassemblerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateSequence(sequence) => {
+                write!(f, "duplicate fragment sequence {sequence}")
+            }
+            Self::OutOfRangeSequence { expected, got } => write!(
+                f,
+                "out-of-range fragment sequence: expected {expected}, got {got}"
+            ),
+            Self::Fragment(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ReassemblerError {}
@@ -0,0 +1,107 @@
+use std::fmt;
+
+/// A single chunk of a fragmented packet body, produced by [fragment]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fragment {
+    /// Zero-based position of this fragment within the original buffer
+    pub sequence: u16,
+    /// Whether additional fragments follow this one
+    pub more: bool,
+    /// The fragment's payload bytes (excludes the header)
+    pub payload: Vec<u8>,
+}
+
+impl Fragment {
+    const HEADER_LEN: usize = 3;
+
+    /// Serializes this fragment to its wire representation: a 2-byte
+    /// big-endian sequence index, a 1-byte "more fragments follow" flag,
+    /// then the payload.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::HEADER_LEN + self.payload.len());
+        bytes.extend_from_slice(&self.sequence.to_be_bytes());
+        bytes.push(self.more as u8);
+        bytes.extend_from_slice(&self.payload);
+        bytes
+    }
+
+    /// Parses a fragment from its wire representation
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FragmentError> {
+        if bytes.len() < Self::HEADER_LEN {
+            return Err(FragmentError::Truncated);
+        }
+
+        Ok(Self {
+            sequence: u16::from_be_bytes([bytes[0], bytes[1]]),
+            more: bytes[2] != 0,
+            payload: bytes[Self::HEADER_LEN..].to_vec(),
+        })
+    }
+}
+
+/// Splits `buf` into a series of [Fragment]s with a payload no larger than
+/// `max_payload` bytes each. A `max_payload` of 0 is treated as 1, so each
+/// fragment of a non-empty `buf` then carries a single byte.
+///
+/// # Examples
+/// ```
+/// use eolib::data::fragment::fragment;
+///
+/// let fragments = fragment(b"hello world", 4);
+/// assert_eq!(fragments.len(), 3);
+/// assert!(!fragments[2].more);
+/// ```
+pub fn fragment(buf: &[u8], max_payload: usize) -> Vec<Fragment> {
+    if buf.is_empty() {
+        return vec![Fragment {
+            sequence: 0,
+            more: false,
+            payload: Vec::new(),
+        }];
+    }
+
+    let chunks: Vec<&[u8]> = buf.chunks(max_payload.max(1)).collect();
+    let last = chunks.len() - 1;
+
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(i, chunk)| Fragment {
+            sequence: i as u16,
+            more: i != last,
+            payload: chunk.to_vec(),
+        })
+        .collect()
+}
+
+/// Errors produced while parsing or validating a [Fragment]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FragmentError {
+    /// The fragment's bytes were shorter than its header
+    Truncated,
+    /// The fragment's payload exceeded a [Reassembler](super::Reassembler)'s
+    /// `max_payload`
+    PayloadTooLarge {
+        sequence: u16,
+        len: usize,
+        max_payload: usize,
+    },
+}
+
+impl fmt::Display for FragmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "fragment is shorter than its header"),
+            Self::PayloadTooLarge {
+                sequence,
+                len,
+                max_payload,
+            } => write!(
+                f,
+                "fragment {sequence} payload of {len} bytes exceeds max_payload of {max_payload}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FragmentError {}
@@ -289,3 +289,16 @@ mod eo_writer;
 pub use eo_writer::{EoWriter, EoWriterError};
 mod eo_serialize;
 pub use eo_serialize::{EoSerialize, EoSerializeError};
+
+mod eo_char;
+pub use eo_char::EoChar;
+mod eo_short;
+pub use eo_short::EoShort;
+mod eo_three;
+pub use eo_three::EoThree;
+mod eo_int;
+pub use eo_int::EoInt;
+
+pub mod fragment;
+
+pub mod compression;
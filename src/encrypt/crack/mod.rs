@@ -0,0 +1,18 @@
+//! Key-recovery tooling for reverse-engineering captured EO traffic with
+//! unknown [encrypt_string](super::encrypt_string)/
+//! [decrypt_string](super::decrypt_string) and
+//! [decrypt_packet](super::decrypt_packet) keys.
+
+mod string_key;
+pub use string_key::{crack_string_key, recover_string_key};
+
+mod packet_swap;
+pub use packet_swap::recover_packet_swap_multiple;
+
+/// A recovered key candidate and how confident the recovery is in it, on a
+/// `0.0..=1.0` scale
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyCandidate {
+    pub key: i32,
+    pub confidence: f64,
+}
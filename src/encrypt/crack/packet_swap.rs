@@ -0,0 +1,48 @@
+use super::KeyCandidate;
+use crate::data::CHAR_MAX;
+use crate::encrypt::decrypt_packet;
+
+/// Scores a decrypted packet by how plausible its leading action/family
+/// bytes are: both must be non-zero and within an
+/// [EoChar](crate::data::EoChar)'s encoded range.
+fn score_header(buf: &[u8]) -> f64 {
+    let valid_byte = |b: u8| b != 0 && (b as i32) <= CHAR_MAX + 1;
+
+    match buf {
+        [action, family, ..] => {
+            [*action, *family].iter().filter(|&&b| valid_byte(b)).count() as f64 / 2.0
+        }
+        _ => 0.0,
+    }
+}
+
+/// Brute-forces the documented `6..=12` packet swap multiple range (the
+/// `magic` parameter of [decrypt_packet](super::super::decrypt_packet))
+/// against a captured packet, scoring each candidate by whether the
+/// decrypted family/action bytes fall in their valid range.
+///
+/// # Examples
+/// ```
+/// use eolib::encrypt::crack::recover_packet_swap_multiple;
+///
+/// let buf = [149, 161, 146, 228, 17, 242, 200, 236, 229, 239, 236, 247, 236, 160, 239, 172];
+/// let candidates = recover_packet_swap_multiple(&buf);
+///
+/// assert_eq!(candidates.len(), 7);
+/// assert!(candidates[0].confidence >= candidates[6].confidence);
+/// ```
+pub fn recover_packet_swap_multiple(encrypted: &[u8]) -> Vec<KeyCandidate> {
+    let mut candidates: Vec<KeyCandidate> = (6..=12)
+        .map(|magic| {
+            let mut buf = encrypted.to_vec();
+            decrypt_packet(&mut buf, magic);
+            KeyCandidate {
+                key: magic,
+                confidence: score_header(&buf),
+            }
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
+    candidates
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use super::KeyCandidate;
+use crate::encrypt::decrypt_string;
+
+/// English letter frequencies (A-Z), used to score decryption candidates
+const ENGLISH_FREQUENCIES: [f64; 26] = [
+    0.08167, 0.01492, 0.02782, 0.04253, 0.12702, 0.02228, 0.02015, 0.06094, 0.06966, 0.00153,
+    0.00772, 0.04025, 0.02406, 0.06749, 0.07507, 0.01929, 0.00095, 0.05987, 0.06327, 0.09056,
+    0.02758, 0.00978, 0.02360, 0.00150, 0.01974, 0.00074,
+];
+
+/// Recovers the per-position `encrypted_value` byte from an
+/// [encrypt_string](super::super::encrypt_string)-produced string, undoing
+/// only the base-24 character pairing and leaving the additive cipher in
+/// place.
+fn encrypted_values(encrypted: &str) -> Vec<u8> {
+    encrypted
+        .as_bytes()
+        .chunks(2)
+        .filter(|chunk| chunk.len() == 2)
+        .map(|chunk| {
+            let first = chunk[0] as i32 - 0x41;
+            let second = chunk[1] as i32 - 0x41;
+            (first * 24 + second) as u8
+        })
+        .collect()
+}
+
+/// The modular inverse of `a` modulo 256, or [None] if `a` is even (and so
+/// shares a factor with 256 and has no inverse).
+fn modinv_256(a: i32) -> Option<i32> {
+    if a % 2 == 0 {
+        return None;
+    }
+
+    let a = a.rem_euclid(256);
+    (1..256).find(|candidate| (a * candidate) % 256 == 1)
+}
+
+/// Recovers the key used by [encrypt_string](super::super::encrypt_string)/
+/// [decrypt_string](super::super::decrypt_string) from a ciphertext and a
+/// known (possibly partial) plaintext.
+///
+/// For each position `i` where the plaintext byte is known, the cipher
+/// `encrypted = (plaintext[i] + (i + 1) * key) mod 256` yields exactly one
+/// key candidate (at positions where `i + 1` is odd and so invertible
+/// mod 256):
+///
+/// `key = (encrypted[i] - plaintext[i]) * modinv(i + 1) mod 256`
+///
+/// Candidates are ranked by the fraction of known positions they're
+/// consistent with.
+///
+/// # Examples
+/// ```
+/// use eolib::encrypt::crack::recover_string_key;
+/// use eolib::encrypt::encrypt_string;
+///
+/// let message = "a secret string";
+/// let encrypted = encrypt_string(message, 12345);
+///
+/// let candidates = recover_string_key(&encrypted, message.as_bytes());
+/// assert_eq!(candidates[0].key, 12345 & 0xFF);
+/// ```
+pub fn recover_string_key(encrypted: &str, known_plaintext: &[u8]) -> Vec<KeyCandidate> {
+    let values = encrypted_values(encrypted);
+    let mut votes: HashMap<i32, usize> = HashMap::new();
+    let mut considered = 0;
+
+    for (i, &plain) in known_plaintext.iter().enumerate() {
+        let Some(&value) = values.get(i) else {
+            break;
+        };
+        let Some(inverse) = modinv_256((i as i32 + 1).rem_euclid(256)) else {
+            continue;
+        };
+
+        considered += 1;
+        let key = ((value as i32 - plain as i32) * inverse).rem_euclid(256);
+        *votes.entry(key).or_insert(0) += 1;
+    }
+
+    let mut candidates: Vec<KeyCandidate> = votes
+        .into_iter()
+        .map(|(key, hits)| KeyCandidate {
+            key,
+            confidence: if considered == 0 {
+                0.0
+            } else {
+                hits as f64 / considered as f64
+            },
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
+    candidates
+}
+
+/// Scores `bytes` on how plausibly it reads as English text: printable
+/// ASCII ratio combined with closeness to expected English letter
+/// frequencies.
+fn score_plaintext(bytes: &[u8]) -> f64 {
+    if bytes.is_empty() {
+        return 0.0;
+    }
+
+    let printable = bytes.iter().filter(|&&b| (0x20..0x7f).contains(&b)).count();
+    let printable_ratio = printable as f64 / bytes.len() as f64;
+
+    let mut counts = [0usize; 26];
+    let mut letters = 0;
+    for &b in bytes {
+        if b.is_ascii_alphabetic() {
+            counts[(b.to_ascii_uppercase() - b'A') as usize] += 1;
+            letters += 1;
+        }
+    }
+
+    if letters == 0 {
+        return printable_ratio * 0.5;
+    }
+
+    let distance: f64 = counts
+        .iter()
+        .zip(ENGLISH_FREQUENCIES.iter())
+        .map(|(&count, &expected)| (count as f64 / letters as f64 - expected).abs())
+        .sum();
+
+    printable_ratio * 0.5 + (1.0 - (distance / 2.0).min(1.0)) * 0.5
+}
+
+/// Brute-forces the [encrypt_string](super::super::encrypt_string)/
+/// [decrypt_string](super::super::decrypt_string) key against ciphertext
+/// with no known plaintext, ranking all 256 candidates by how closely their
+/// decryption resembles English text.
+///
+/// # Examples
+/// ```
+/// use eolib::encrypt::crack::crack_string_key;
+/// use eolib::encrypt::encrypt_string;
+///
+/// let encrypted = encrypt_string("hello there friend", 42);
+/// let candidates = crack_string_key(&encrypted);
+///
+/// assert_eq!(candidates[0].key, 42);
+/// ```
+pub fn crack_string_key(encrypted: &str) -> Vec<KeyCandidate> {
+    let mut candidates: Vec<KeyCandidate> = (0..256)
+        .map(|key| KeyCandidate {
+            key,
+            confidence: score_plaintext(decrypt_string(encrypted, key).as_bytes()),
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap());
+    candidates
+}
@@ -1,12 +1,17 @@
+use super::encrypt_packet::{flip_msb, swap_multiples};
 use super::valid_for_encryption;
 
 /// Decrypts a packet.
 ///
-/// Packets are decrypted in three steps:
-/// 1. Flipping
-/// 2. Interleaving
+/// Packets are decrypted in three steps, the reverse of [encrypt_packet](super::encrypt_packet):
+/// 1. Deinterleaving
+/// 2. Flipping
 /// 3. "dickwinding"
 ///
+/// ## Deinterleaving
+/// The reverse of interleaving: woven bytes are restored to their original
+/// positions.
+///
 /// ## Flipping
 /// Each byte of the packet has their most significant bits flipped
 /// ```text
@@ -15,22 +20,14 @@ use super::valid_for_encryption;
 /// }
 /// ```
 ///
-/// ## Interleaving
-/// Bytes are "woven" in to each-other e.g.
-/// ```text
-/// abcde -> acedb
-///   or
-/// abcdef -> acefdb
-/// ```
-///
 /// ## Dickwinding
 /// This was named by Sausage and first implemented in the EOProxy project.
 /// There are two numbers sent from the server to the client on connect
 /// between 6 and 12 that represent a "send packet swap multiple"
 /// and a "receive packet swap multiple".
 ///
-/// Any two bytes next to each other in the packet data that are
-/// divisible by that number are swapped.
+/// Any run of adjacent bytes that are all divisible by that number is
+/// reversed in place (this operation is its own inverse).
 ///
 /// For more details see [Packet](https://eoserv.net/wiki/wiki?page=Packet)
 ///
@@ -44,16 +41,23 @@ use super::valid_for_encryption;
 ///
 /// assert_eq!(buf, [21, 18, 145, 72, 101, 108, 108, 111, 44, 32, 119, 111, 114, 108, 100, 33]);
 /// ```
-pub fn decrypt_packet(buf: &mut [u8], key: i32, magic: i32) {
+pub fn decrypt_packet(buf: &mut [u8], magic: i32) {
     if !valid_for_encryption(buf) {
         return;
     }
 
-    for i in 1..=buf.len() {
-        let mut val = buf[i - 1] as i32;
+    deinterleave(buf);
+    flip_msb(buf);
+    swap_multiples(buf, magic);
+}
 
-        val = (((val + 253) % 256) - key - i as i32) & 0xFF;
+/// Reverses `encrypt_packet`'s interleaving step, restoring woven bytes to
+/// their original positions
+fn deinterleave(buf: &mut [u8]) {
+    let original = buf.to_vec();
+    let order = (0..buf.len()).step_by(2).chain((1..buf.len()).step_by(2).rev());
 
-        buf[i - 1] = val as u8;
+    for (dest, src) in order.enumerate() {
+        buf[dest] = original[src];
     }
 }
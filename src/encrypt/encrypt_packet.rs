@@ -13,8 +13,16 @@ use super::valid_for_encryption;
 /// between 6 and 12 that represent a "send packet swap multiple"
 /// and a "receive packet swap multiple".
 ///
-/// Any two bytes next to each other in the packet data that are
-/// divisible by that number are swapped.
+/// Any run of adjacent bytes that are all divisible by that number is
+/// reversed in place.
+/// ```text
+/// for i in 0..=length {
+///     if i == length || bytes[i] % multiple != 0 {
+///         reverse(bytes[start..i]);
+///         start = i + 1;
+///     }
+/// }
+/// ```
 ///
 /// ## Flipping
 /// Each byte of the packet has their most significant bits flipped
@@ -44,16 +52,54 @@ use super::valid_for_encryption;
 ///
 /// assert_eq!(buf, [149, 161, 146, 228, 17, 242, 200, 236, 229, 239, 236, 247, 236, 160, 239, 172]);
 /// ```
-pub fn encrypt_packet(buf: &mut [u8], key: i32) {
+pub fn encrypt_packet(buf: &mut [u8], magic: i32) {
     if !valid_for_encryption(buf) {
         return;
     }
 
-    for i in 1..=buf.len() {
-        let mut val = buf[i - 1] as i32;
+    swap_multiples(buf, magic);
+    flip_msb(buf);
+    interleave(buf);
+}
+
+/// Reverses each run of adjacent bytes that are all divisible by `multiple`
+pub(super) fn swap_multiples(buf: &mut [u8], multiple: i32) {
+    if multiple == 0 {
+        return;
+    }
+
+    let mut run_start: Option<usize> = None;
+
+    for i in 0..=buf.len() {
+        let divisible = i < buf.len() && (buf[i] as i32) % multiple == 0;
 
-        val = (((val + 3) % 256) + key + i as i32) & 0xFF;
+        if divisible {
+            if run_start.is_none() {
+                run_start = Some(i);
+            }
+        } else if let Some(start) = run_start.take() {
+            buf[start..i].reverse();
+        }
+    }
+}
+
+/// Flips the most significant bit of every byte
+pub(super) fn flip_msb(buf: &mut [u8]) {
+    for b in buf.iter_mut() {
+        *b ^= 0x80;
+    }
+}
+
+/// Weaves bytes together: `abcde -> aebdc`, `abcdef -> afbecd`
+pub(super) fn interleave(buf: &mut [u8]) {
+    let original = buf.to_vec();
+    let mut src = original.iter();
+
+    for i in (0..buf.len()).step_by(2) {
+        buf[i] = *src.next().unwrap();
+    }
 
-        buf[i - 1] = val as u8;
+    for i in (1..buf.len()).step_by(2).rev() {
+        buf[i] = *src.next().unwrap();
     }
 }
@@ -11,6 +11,8 @@ pub use encrypt_string::encrypt_string;
 mod decrypt_string;
 pub use decrypt_string::decrypt_string;
 
+pub mod crack;
+
 pub(crate) fn valid_for_encryption(buf: &[u8]) -> bool {
     buf.len() > 2 && buf[0..=1] != [0xff, 0xff]
 }